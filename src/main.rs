@@ -3,19 +3,148 @@ use clap::Parser;
 use crossterm::{
     cursor::{Hide, MoveTo, Show},
     event::{self, Event, KeyCode, KeyEventKind},
-    execute,
+    execute, queue,
     style::{Color, Print, ResetColor, SetForegroundColor},
     terminal::{disable_raw_mode, enable_raw_mode, size, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
 use rand::prelude::*;
 use std::{
-    io::{stdout, Write},
+    io::{stdout, BufWriter, Write},
     time::{Duration, Instant},
 };
-use sysinfo::System;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc, Mutex};
+use sysinfo::{Networks, System};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
 use tokio::time::sleep;
 use colorgrad::{self};
 
+/// Block glyphs used to draw compact inline sparklines, lowest to highest.
+const SPARK_CHARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Built-in color themes, cycled with the 't' hotkey alongside any
+/// user-supplied gradient file.
+const THEME_PRESETS: [&str; 4] = ["fire", "ocean", "matrix", "rainbow"];
+
+fn preset_gradient(name: &str) -> colorgrad::Gradient {
+    match name {
+        "fire" => colorgrad::turbo(),
+        "ocean" => colorgrad::viridis(),
+        "matrix" => colorgrad::CustomGradient::new()
+            .colors(&[
+                colorgrad::Color::from_html("#000000").unwrap(),
+                colorgrad::Color::from_html("#00ff00").unwrap(),
+            ])
+            .build()
+            .expect("matrix preset is a valid gradient"),
+        "rainbow" => colorgrad::rainbow(),
+        _ => colorgrad::turbo(),
+    }
+}
+
+/// Load a gradient from a plain-text file: one hex color stop per
+/// non-empty line (e.g. "#ff8800"), built via `colorgrad::CustomGradient`.
+fn load_gradient_file(path: &Path) -> Result<colorgrad::Gradient> {
+    let content = std::fs::read_to_string(path)?;
+    let colors: Vec<colorgrad::Color> = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            colorgrad::Color::from_html(line)
+                .map_err(|e| anyhow::anyhow!("invalid color stop '{line}' in {path:?}: {e}"))
+        })
+        .collect::<Result<_>>()?;
+    anyhow::ensure!(
+        colors.len() >= 2,
+        "gradient file {path:?} needs at least 2 color stops"
+    );
+    Ok(colorgrad::CustomGradient::new().colors(&colors).build()?)
+}
+
+/// A single measurable quantity tracked over a rolling window of recent
+/// frames (e.g. a CPU core's usage, or the time spent in `update()`).
+///
+/// Modeled on WebRender's profiler counters: cheap to push to every frame,
+/// and able to render itself as a sparkline plus an avg/max summary.
+struct Counter {
+    label: String,
+    unit: &'static str,
+    samples: VecDeque<f32>,
+    window: usize,
+}
+
+impl Counter {
+    fn new(label: &str, unit: &'static str, window: usize) -> Self {
+        Self {
+            label: label.to_string(),
+            unit,
+            samples: VecDeque::with_capacity(window),
+            window,
+        }
+    }
+
+    fn push(&mut self, value: f32) {
+        if self.samples.len() == self.window {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(value);
+    }
+
+    fn avg(&self) -> f32 {
+        if self.samples.is_empty() {
+            0.0
+        } else {
+            self.samples.iter().sum::<f32>() / self.samples.len() as f32
+        }
+    }
+
+    fn max(&self) -> f32 {
+        self.samples.iter().cloned().fold(0.0, f32::max)
+    }
+
+    /// Render the samples as a compact inline sparkline, scaled against an
+    /// explicit ceiling (so callers can line several counters up against a
+    /// shared budget instead of each other's local max).
+    fn sparkline_against(&self, ceiling: f32) -> String {
+        let ceiling = ceiling.max(1e-6);
+        self.samples
+            .iter()
+            .map(|&v| {
+                let t = (v / ceiling).clamp(0.0, 1.0);
+                SPARK_CHARS[(t * (SPARK_CHARS.len() - 1) as f32).round() as usize]
+            })
+            .collect()
+    }
+
+    fn sparkline(&self) -> String {
+        self.sparkline_against(self.max())
+    }
+
+    /// Render the samples as a sparkline pinned to a fixed budget ceiling
+    /// instead of the window max, so the budget line stays put and
+    /// headroom stays readable even once some samples blow past it.
+    /// Samples over budget are drawn as `!` in place, marking exactly
+    /// which frames overran rather than rescaling the whole graph.
+    fn sparkline_against_budget(&self, budget: f32) -> String {
+        let budget = budget.max(1e-6);
+        self.samples
+            .iter()
+            .map(|&v| {
+                if v > budget {
+                    '!'
+                } else {
+                    let t = (v / budget).clamp(0.0, 1.0);
+                    SPARK_CHARS[(t * (SPARK_CHARS.len() - 1) as f32).round() as usize]
+                }
+            })
+            .collect()
+    }
+}
+
 /// LiveScope - Real-time System Performance Art Visualizer
 #[derive(Parser)]
 #[command(name = "livescope")]
@@ -29,13 +158,397 @@ struct Args {
     #[arg(short, long)]
     particles: bool,
     
-    /// Color theme (fire, ocean, matrix, rainbow)
+    /// Color theme: a preset name (fire, ocean, matrix, rainbow) or a path
+    /// to a gradient file (one hex color stop per line), hot-reloaded live
     #[arg(short, long, default_value = "fire")]
     theme: String,
+
+    /// Display mode: "art" (CPU/memory visuals) or "processes" (top-style table)
+    #[arg(short, long, default_value = "art")]
+    mode: String,
+
+    /// Serve Prometheus metrics (/metrics) and an SSE snapshot stream
+    /// (/stream) on this port, alongside the terminal UI
+    #[arg(long)]
+    serve: Option<u16>,
+
+    /// Layout DSL describing how regions divide the screen, e.g.
+    /// "h(cpu:60%,v(mem,particles):40%)". Defaults to the classic equal
+    /// thirds of cpu/mem/profiler.
+    #[arg(long)]
+    layout: Option<String>,
+}
+
+/// A point-in-time copy of the data LiveScope visualizes, shared with the
+/// `--serve` HTTP endpoints so they never touch `LiveScope` directly.
+#[derive(Clone, Default)]
+struct MetricsSnapshot {
+    cpu_per_core: Vec<f32>,
+    mem_used: u64,
+    mem_total: u64,
+    net_rx: u64,
+    net_tx: u64,
+}
+
+impl MetricsSnapshot {
+    fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        out.push_str("# HELP livescope_cpu_percent Per-core CPU usage percentage\n");
+        out.push_str("# TYPE livescope_cpu_percent gauge\n");
+        for (i, pct) in self.cpu_per_core.iter().enumerate() {
+            out.push_str(&format!("livescope_cpu_percent{{core=\"{i}\"}} {pct}\n"));
+        }
+        out.push_str("# HELP livescope_memory_bytes Memory usage in bytes\n");
+        out.push_str("# TYPE livescope_memory_bytes gauge\n");
+        out.push_str(&format!("livescope_memory_bytes{{state=\"used\"}} {}\n", self.mem_used));
+        out.push_str(&format!("livescope_memory_bytes{{state=\"total\"}} {}\n", self.mem_total));
+        out.push_str("# HELP livescope_network_bytes Network bytes observed in the last refresh tick\n");
+        out.push_str("# TYPE livescope_network_bytes gauge\n");
+        out.push_str(&format!("livescope_network_bytes{{direction=\"rx\"}} {}\n", self.net_rx));
+        out.push_str(&format!("livescope_network_bytes{{direction=\"tx\"}} {}\n", self.net_tx));
+        out
+    }
+
+    fn to_json(&self) -> String {
+        let cpu = self
+            .cpu_per_core
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        format!(
+            "{{\"cpu_per_core\":[{}],\"mem_used\":{},\"mem_total\":{},\"net_rx\":{},\"net_tx\":{}}}",
+            cpu, self.mem_used, self.mem_total, self.net_rx, self.net_tx
+        )
+    }
+}
+
+/// What the screen renders: the layout's decorative art visuals, or a
+/// sortable process table that takes over the whole screen. Toggled at
+/// startup via `--mode` or at runtime with the 'm' hotkey.
+#[derive(Clone, Copy, PartialEq)]
+enum ViewMode {
+    Art,
+    Processes,
+}
+
+impl ViewMode {
+    fn from_arg(mode: &str) -> Self {
+        match mode {
+            "processes" => ViewMode::Processes,
+            _ => ViewMode::Art,
+        }
+    }
+
+    fn toggle(self) -> Self {
+        match self {
+            ViewMode::Art => ViewMode::Processes,
+            ViewMode::Processes => ViewMode::Art,
+        }
+    }
+}
+
+/// Which column the process table is sorted by, cycled with 's'.
+#[derive(Clone, Copy, PartialEq)]
+enum ProcessSort {
+    Cpu,
+    Memory,
+    Name,
+}
+
+impl ProcessSort {
+    fn cycle(self) -> Self {
+        match self {
+            ProcessSort::Cpu => ProcessSort::Memory,
+            ProcessSort::Memory => ProcessSort::Name,
+            ProcessSort::Name => ProcessSort::Cpu,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            ProcessSort::Cpu => "cpu",
+            ProcessSort::Memory => "mem",
+            ProcessSort::Name => "name",
+        }
+    }
+}
+
+/// A single screen cell in the back/front buffers: a glyph plus its
+/// foreground color. Two cells are equal when both glyph and color match.
+#[derive(Clone, Copy, PartialEq)]
+struct Cell {
+    ch: char,
+    fg: (u8, u8, u8),
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self { ch: ' ', fg: (0, 0, 0) }
+    }
+}
+
+/// A rectangular region of the terminal, in cells.
+#[derive(Clone, Copy, Debug)]
+struct Rect {
+    x: u16,
+    y: u16,
+    w: u16,
+    h: u16,
+}
+
+impl Rect {
+    fn full(width: u16, height: u16) -> Self {
+        Self { x: 0, y: 0, w: width, h: height }
+    }
+}
+
+/// A visual module that can be bound to a layout region.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum RendererKind {
+    CpuHeatmap,
+    MemoryWave,
+    Particles,
+    ProcessTable,
+    Profiler,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum SplitDirection {
+    Horizontal,
+    Vertical,
+}
+
+/// How much of its split a region gets. `Auto` regions share whatever
+/// space is left over after `Fixed` and `Percent` siblings are resolved.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Constraint {
+    Percent(u16),
+    Fixed(u16),
+    Auto,
+}
+
+/// A node in the layout tree: either a renderer bound to a region, or a
+/// split dividing a region among further nodes. Resolved to concrete
+/// `Rect`s against the current terminal size every frame, so resizes are
+/// picked up for free without any dedicated resize handling.
+#[derive(Clone, Debug)]
+enum LayoutNode {
+    Leaf(RendererKind),
+    Split(SplitDirection, Vec<(Constraint, LayoutNode)>),
+}
+
+impl LayoutNode {
+    /// Default layout: the CPU heatmap, memory wave and profiler stacked
+    /// in equal thirds — matching the tool's original fixed-thirds look,
+    /// but now just one particular layout instead of the only one.
+    fn default_layout() -> Self {
+        LayoutNode::Split(
+            SplitDirection::Vertical,
+            vec![
+                (Constraint::Auto, LayoutNode::Leaf(RendererKind::CpuHeatmap)),
+                (Constraint::Auto, LayoutNode::Leaf(RendererKind::MemoryWave)),
+                (Constraint::Auto, LayoutNode::Leaf(RendererKind::Profiler)),
+            ],
+        )
+    }
+
+    /// Resolve this node (and its descendants) against `rect`, appending
+    /// `(renderer, region)` pairs to `out` in draw order.
+    fn resolve(&self, rect: Rect, out: &mut Vec<(RendererKind, Rect)>) {
+        match self {
+            LayoutNode::Leaf(kind) => out.push((*kind, rect)),
+            LayoutNode::Split(direction, children) => {
+                let total = match direction {
+                    SplitDirection::Horizontal => rect.w,
+                    SplitDirection::Vertical => rect.h,
+                };
+
+                let fixed_sum: u16 = children
+                    .iter()
+                    .map(|(c, _)| if let Constraint::Fixed(n) = c { *n } else { 0 })
+                    .sum();
+                let remaining = total.saturating_sub(fixed_sum);
+                let percent_sum: u16 = children
+                    .iter()
+                    .map(|(c, _)| if let Constraint::Percent(p) = c { *p } else { 0 })
+                    .sum();
+                let percent_cells = (remaining as u32 * percent_sum.min(100) as u32 / 100) as u16;
+                let auto_count = children
+                    .iter()
+                    .filter(|(c, _)| matches!(c, Constraint::Auto))
+                    .count() as u16;
+                let auto_cells = remaining.saturating_sub(percent_cells);
+                let auto_share = auto_cells.checked_div(auto_count).unwrap_or(0);
+
+                // Integer division (auto_share, percent rounding) can leave
+                // a few cells uncovered; hand them to the last child so the
+                // children tile `rect` exactly rather than leaving trailing
+                // blank rows/columns. Over-constrained splits (fixed sizes
+                // above `total`, or percents above 100) are not shrunk and
+                // extend past `rect`.
+                let mut sizes: Vec<u16> = children
+                    .iter()
+                    .map(|(constraint, _)| match constraint {
+                        Constraint::Fixed(n) => *n,
+                        Constraint::Percent(p) => (remaining as u32 * *p as u32 / 100) as u16,
+                        Constraint::Auto => auto_share,
+                    })
+                    .collect();
+                let used: u16 = sizes.iter().sum();
+                if let Some(last) = sizes.last_mut() {
+                    *last += total.saturating_sub(used);
+                }
+
+                let mut offset = 0u16;
+                for ((_, node), size) in children.iter().zip(sizes) {
+                    let child_rect = match direction {
+                        SplitDirection::Horizontal => Rect { x: rect.x + offset, y: rect.y, w: size, h: rect.h },
+                        SplitDirection::Vertical => Rect { x: rect.x, y: rect.y + offset, w: rect.w, h: size },
+                    };
+                    node.resolve(child_rect, out);
+                    offset += size;
+                }
+            }
+        }
+    }
+
+    /// Whether `kind` is bound anywhere in this layout tree.
+    fn contains(&self, kind: RendererKind) -> bool {
+        match self {
+            LayoutNode::Leaf(k) => *k == kind,
+            LayoutNode::Split(_, children) => children.iter().any(|(_, node)| node.contains(kind)),
+        }
+    }
+}
+
+/// Parse the small layout DSL accepted by `--layout`:
+///
+/// ```text
+/// node       := split | leaf
+/// split      := ('h' | 'v') '(' child (',' child)* ')'
+/// child      := node (':' constraint)?
+/// constraint := NUMBER '%' | NUMBER
+/// leaf       := 'cpu' | 'mem' | 'particles' | 'processes' | 'profiler'
+/// ```
+///
+/// e.g. `h(cpu:60%,v(mem,particles):40%)` — CPU on the left taking 60% of
+/// the width, a memory/particle column stacked evenly on the right. A
+/// number without `%` is a fixed size in cells.
+fn parse_layout(spec: &str) -> Result<LayoutNode> {
+    struct Parser<'a> {
+        bytes: &'a [u8],
+        pos: usize,
+    }
+
+    impl<'a> Parser<'a> {
+        fn peek(&self) -> Option<u8> {
+            self.bytes.get(self.pos).copied()
+        }
+
+        fn skip_ws(&mut self) {
+            while matches!(self.peek(), Some(b' ')) {
+                self.pos += 1;
+            }
+        }
+
+        fn expect(&mut self, c: u8) -> Result<()> {
+            if self.peek() == Some(c) {
+                self.pos += 1;
+                Ok(())
+            } else {
+                anyhow::bail!("expected '{}' at position {}", c as char, self.pos)
+            }
+        }
+
+        fn take_while(&mut self, pred: impl Fn(u8) -> bool) -> &'a str {
+            let start = self.pos;
+            while self.peek().is_some_and(&pred) {
+                self.pos += 1;
+            }
+            std::str::from_utf8(&self.bytes[start..self.pos]).unwrap_or("")
+        }
+
+        fn parse_node(&mut self) -> Result<LayoutNode> {
+            self.skip_ws();
+            match self.peek() {
+                Some(b'h') | Some(b'v') if self.bytes.get(self.pos + 1) == Some(&b'(') => self.parse_split(),
+                _ => self.parse_leaf(),
+            }
+        }
+
+        fn parse_split(&mut self) -> Result<LayoutNode> {
+            let direction = match self.peek() {
+                Some(b'h') => SplitDirection::Horizontal,
+                Some(b'v') => SplitDirection::Vertical,
+                _ => anyhow::bail!("expected 'h' or 'v' split direction"),
+            };
+            self.pos += 1;
+            self.expect(b'(')?;
+
+            let mut children = Vec::new();
+            loop {
+                let node = self.parse_node()?;
+                self.skip_ws();
+                let constraint = if self.peek() == Some(b':') {
+                    self.pos += 1;
+                    self.parse_constraint()?
+                } else {
+                    Constraint::Auto
+                };
+                children.push((constraint, node));
+
+                self.skip_ws();
+                match self.peek() {
+                    Some(b',') => self.pos += 1,
+                    Some(b')') => {
+                        self.pos += 1;
+                        break;
+                    }
+                    _ => anyhow::bail!("expected ',' or ')' at position {}", self.pos),
+                }
+            }
+            Ok(LayoutNode::Split(direction, children))
+        }
+
+        fn parse_constraint(&mut self) -> Result<Constraint> {
+            self.skip_ws();
+            let digits = self.take_while(|c| c.is_ascii_digit());
+            anyhow::ensure!(!digits.is_empty(), "expected a number at position {}", self.pos);
+            let n: u16 = digits.parse()?;
+            if self.peek() == Some(b'%') {
+                self.pos += 1;
+                Ok(Constraint::Percent(n))
+            } else {
+                Ok(Constraint::Fixed(n))
+            }
+        }
+
+        fn parse_leaf(&mut self) -> Result<LayoutNode> {
+            self.skip_ws();
+            let name = self.take_while(|c| c.is_ascii_alphabetic());
+            let kind = match name {
+                "cpu" => RendererKind::CpuHeatmap,
+                "mem" => RendererKind::MemoryWave,
+                "particles" => RendererKind::Particles,
+                "processes" => RendererKind::ProcessTable,
+                "profiler" => RendererKind::Profiler,
+                other => anyhow::bail!("unknown layout region '{other}'"),
+            };
+            Ok(LayoutNode::Leaf(kind))
+        }
+    }
+
+    let mut parser = Parser { bytes: spec.as_bytes(), pos: 0 };
+    let node = parser.parse_node()?;
+    parser.skip_ws();
+    anyhow::ensure!(parser.pos == parser.bytes.len(), "unexpected trailing input in layout spec");
+    Ok(node)
 }
 
 struct LiveScope {
     system: System,
+    networks: Networks,
     width: u16,
     height: u16,
     cpu_history: Vec<Vec<f32>>,
@@ -44,6 +557,35 @@ struct LiveScope {
     particles_enabled: bool,
     gradient: colorgrad::Gradient,
     rng: ThreadRng,
+    /// Rolling-window profiling counters: one per CPU core, then memory,
+    /// particle count, `update()` time and `render()` time (ms).
+    counters: Vec<Counter>,
+    idx_memory: usize,
+    idx_particles: usize,
+    idx_update_ms: usize,
+    idx_render_ms: usize,
+    refresh_budget_ms: f32,
+    /// What `render()` composes this frame, diffed against `front_buffer`
+    /// so only changed cells are ever written to the terminal.
+    back_buffer: Vec<Cell>,
+    front_buffer: Vec<Cell>,
+    view_mode: ViewMode,
+    process_sort: ProcessSort,
+    process_scroll: usize,
+    /// The watched gradient file, if `--theme` pointed at one instead of a
+    /// preset name.
+    theme_file: Option<PathBuf>,
+    /// Index into `THEME_PRESETS`, or `THEME_PRESETS.len()` for the
+    /// watched file — cycled with the 't' hotkey.
+    theme_cursor: usize,
+    theme_rx: mpsc::Receiver<notify::Result<notify::Event>>,
+    _theme_watcher: Option<RecommendedWatcher>,
+    /// Bytes observed in the last refresh tick, also exposed via `--serve`.
+    last_net_rx: u64,
+    last_net_tx: u64,
+    /// The active region layout, resolved against the current terminal
+    /// size fresh every frame so resizes need no special handling here.
+    layout: LayoutNode,
 }
 
 struct Particle {
@@ -53,33 +595,76 @@ struct Particle {
     vy: f32,
     life: f32,
     char: char,
+    /// Per-tick velocity damping factor, applied before gravity.
+    friction: f32,
+    /// Colors sampled from the active gradient at spawn time, walked by
+    /// remaining `life` so a particle's hue drifts as it ages.
+    color_ramp: Vec<(u8, u8, u8)>,
 }
 
 impl LiveScope {
-    fn new(theme: &str, particles_enabled: bool) -> Result<Self> {
+    fn new(
+        theme: &str,
+        particles_enabled: bool,
+        refresh_ms: u64,
+        mode: &str,
+        layout_spec: Option<&str>,
+    ) -> Result<Self> {
+        let layout = match layout_spec {
+            Some(spec) => parse_layout(spec)?,
+            None => LayoutNode::default_layout(),
+        };
         let (width, height) = size()?;
         let mut system = System::new_all();
         system.refresh_all();
-        
+        let networks = Networks::new_with_refreshed_list();
+
         let cpu_count = system.cpus().len();
         let cpu_history = vec![vec![0.0; width as usize]; cpu_count];
         let memory_wave = vec![0.0; width as usize];
-        
-        let gradient = match theme {
-            "fire" => colorgrad::turbo(),
-            "ocean" => colorgrad::viridis(), 
-            "matrix" => colorgrad::CustomGradient::new()
-                .colors(&[
-                    colorgrad::Color::from_html("#000000").unwrap(),
-                    colorgrad::Color::from_html("#00ff00").unwrap(),
-                ])
-                .build()?,
-            "rainbow" => colorgrad::rainbow(),
-            _ => colorgrad::turbo(),
+
+        // ~500ms of rolling history, but never fewer than 8 samples (so
+        // slow refresh rates keep a longer window).
+        let window = ((500 / refresh_ms.max(1)) as usize).max(8);
+        let mut counters: Vec<Counter> = (0..cpu_count)
+            .map(|i| Counter::new(&format!("cpu{i}"), "%", window))
+            .collect();
+        let idx_memory = counters.len();
+        counters.push(Counter::new("mem", "%", window));
+        let idx_particles = counters.len();
+        counters.push(Counter::new("particles", "", window));
+        let idx_update_ms = counters.len();
+        counters.push(Counter::new("update", "ms", window));
+        let idx_render_ms = counters.len();
+        counters.push(Counter::new("render", "ms", window));
+
+        // `--theme` accepts either a preset name or a path to a gradient
+        // file (one hex color stop per line), hot-reloaded via `notify`.
+        let theme_path = PathBuf::from(theme);
+        let (gradient, theme_file, theme_cursor) = if theme_path.is_file() {
+            (load_gradient_file(&theme_path)?, Some(theme_path), THEME_PRESETS.len())
+        } else {
+            let cursor = THEME_PRESETS.iter().position(|&p| p == theme).unwrap_or(0);
+            (preset_gradient(THEME_PRESETS[cursor]), None, cursor)
         };
-        
+
+        let (theme_tx, theme_rx) = mpsc::channel();
+        let theme_watcher = match &theme_file {
+            Some(path) => {
+                let mut watcher = notify::recommended_watcher(move |res| {
+                    let _ = theme_tx.send(res);
+                })?;
+                watcher.watch(path, RecursiveMode::NonRecursive)?;
+                Some(watcher)
+            }
+            None => None,
+        };
+
+        let cell_count = width as usize * height as usize;
+
         Ok(Self {
             system,
+            networks,
             width,
             height,
             cpu_history,
@@ -88,13 +673,83 @@ impl LiveScope {
             particles_enabled,
             gradient,
             rng: thread_rng(),
+            counters,
+            idx_memory,
+            idx_particles,
+            idx_update_ms,
+            idx_render_ms,
+            refresh_budget_ms: refresh_ms as f32,
+            back_buffer: vec![Cell::default(); cell_count],
+            // Start the front buffer full of a sentinel that no real frame
+            // will produce, so the very first render draws every cell.
+            front_buffer: vec![Cell { ch: '\0', fg: (0, 0, 0) }; cell_count],
+            view_mode: ViewMode::from_arg(mode),
+            process_sort: ProcessSort::Cpu,
+            process_scroll: 0,
+            theme_file,
+            theme_cursor,
+            theme_rx,
+            _theme_watcher: theme_watcher,
+            last_net_rx: 0,
+            last_net_tx: 0,
+            layout,
         })
     }
-    
+
+    /// A point-in-time copy of the current metrics, for the `--serve`
+    /// HTTP endpoints.
+    fn snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            cpu_per_core: self.system.cpus().iter().map(|c| c.cpu_usage()).collect(),
+            mem_used: self.system.used_memory(),
+            mem_total: self.system.total_memory(),
+            net_rx: self.last_net_rx,
+            net_tx: self.last_net_tx,
+        }
+    }
+
+    /// Reallocate the cell buffers and per-column histories for a new
+    /// terminal size. Called when a `Resize` event arrives.
+    fn resize(&mut self, width: u16, height: u16) {
+        self.width = width;
+        self.height = height;
+
+        for history in &mut self.cpu_history {
+            history.resize(width as usize, 0.0);
+        }
+        self.memory_wave.resize(width as usize, 0.0);
+
+        let cell_count = width as usize * height as usize;
+        self.back_buffer = vec![Cell::default(); cell_count];
+        self.front_buffer = vec![Cell { ch: '\0', fg: (0, 0, 0) }; cell_count];
+    }
+
+    /// Write a single glyph into the back buffer at `(x, y)`, if in bounds.
+    fn set_cell(&mut self, x: u16, y: u16, ch: char, fg: (u8, u8, u8)) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        let idx = y as usize * self.width as usize + x as usize;
+        self.back_buffer[idx] = Cell { ch, fg };
+    }
+
+    /// Write each character of `s` into successive cells starting at
+    /// `(x, y)`, for rendering text rows (info panel, process table, ...).
+    fn set_str(&mut self, x: u16, y: u16, s: &str, fg: (u8, u8, u8)) {
+        for (i, ch) in s.chars().enumerate() {
+            self.set_cell(x + i as u16, y, ch, fg);
+        }
+    }
+
     fn update(&mut self) {
+        self.reload_theme_if_changed();
+
         self.system.refresh_cpu();
         self.system.refresh_memory();
-        
+        if self.view_mode == ViewMode::Processes || self.layout.contains(RendererKind::ProcessTable) {
+            self.system.refresh_processes();
+        }
+
         // Update CPU history (shift left and add new values)
         for (i, cpu) in self.system.cpus().iter().enumerate() {
             if i < self.cpu_history.len() {
@@ -103,121 +758,260 @@ impl LiveScope {
                     *last = cpu.cpu_usage();
                 }
             }
+            if i < self.counters.len() {
+                self.counters[i].push(cpu.cpu_usage());
+            }
         }
-        
+
         // Update memory wave
         let memory_percent = self.system.used_memory() as f64 / self.system.total_memory() as f64;
         self.memory_wave.rotate_left(1);
         if let Some(last) = self.memory_wave.last_mut() {
             *last = memory_percent as f32;
         }
-        
-        // Update particles
+        self.counters[self.idx_memory].push(memory_percent as f32 * 100.0);
+
+        // Update particles: friction damps velocity, gravity pulls down,
+        // then bounce (instead of dying) off the terminal edges.
+        let (width, height) = (self.width as f32, self.height as f32);
         self.particles.retain_mut(|p| {
+            p.vx *= p.friction;
+            p.vy *= p.friction;
+            p.vy += 0.1; // gravity
             p.x += p.vx;
             p.y += p.vy;
+
+            if p.x < 0.0 {
+                p.x = 0.0;
+                p.vx = -p.vx * 0.6;
+            } else if p.x >= width {
+                p.x = width - 1.0;
+                p.vx = -p.vx * 0.6;
+            }
+            if p.y < 0.0 {
+                p.y = 0.0;
+                p.vy = -p.vy * 0.6;
+            } else if p.y >= height {
+                p.y = height - 1.0;
+                p.vy = -p.vy * 0.6;
+            }
+
             p.life -= 0.02;
-            p.vy += 0.1; // gravity
-            p.life > 0.0 && p.x >= 0.0 && p.x < self.width as f32 && p.y >= 0.0 && p.y < self.height as f32
+            p.life > 0.0
         });
-        
-        // Spawn new particles based on network activity
-        if self.particles_enabled && self.rng.gen_bool(0.3) {
-            self.particles.push(Particle {
-                x: self.rng.gen_range(0.0..self.width as f32),
-                y: 0.0,
-                vx: self.rng.gen_range(-0.5..0.5),
-                vy: self.rng.gen_range(0.1..0.5),
-                life: 1.0,
-                char: ['●', '○', '◆', '◇', '★', '☆'][self.rng.gen_range(0..6)],
+
+        // Spawn particles proportional to real network throughput: RX
+        // rises from the bottom, TX falls from the top.
+        self.networks.refresh();
+        let (rx_bytes, tx_bytes) = self
+            .networks
+            .iter()
+            .fold((0u64, 0u64), |(rx, tx), (_name, data)| {
+                (rx + data.received(), tx + data.transmitted())
             });
+        self.last_net_rx = rx_bytes;
+        self.last_net_tx = tx_bytes;
+
+        if self.particles_enabled {
+            const BYTES_PER_PARTICLE: f32 = 4096.0;
+            const MAX_SPAWNS_PER_TICK: usize = 8;
+
+            let rx_spawns = ((rx_bytes as f32 / BYTES_PER_PARTICLE) as usize).min(MAX_SPAWNS_PER_TICK);
+            for _ in 0..rx_spawns {
+                self.spawn_particle(true, rx_bytes as f32);
+            }
+            let tx_spawns = ((tx_bytes as f32 / BYTES_PER_PARTICLE) as usize).min(MAX_SPAWNS_PER_TICK);
+            for _ in 0..tx_spawns {
+                self.spawn_particle(false, tx_bytes as f32);
+            }
         }
+
+        self.counters[self.idx_particles].push(self.particles.len() as f32);
     }
-    
+
+    /// Spawn one network-activity particle. `rising` particles (RX) start
+    /// at the bottom and move up; falling particles (TX) start at the top
+    /// and move down. `throughput` (bytes this tick) scales initial speed.
+    fn spawn_particle(&mut self, rising: bool, throughput: f32) {
+        let speed = (throughput / 2048.0).clamp(0.2, 3.0);
+        let (y, vy) = if rising {
+            (self.height as f32 - 1.0, -speed)
+        } else {
+            (0.0, speed)
+        };
+
+        let color_ramp = (0..8)
+            .map(|i| {
+                let c = self.gradient.at(i as f64 / 7.0).to_rgba8();
+                (c[0], c[1], c[2])
+            })
+            .collect();
+
+        self.particles.push(Particle {
+            x: self.rng.gen_range(0.0..self.width as f32),
+            y,
+            vx: self.rng.gen_range(-0.5..0.5),
+            vy,
+            life: 1.0,
+            char: ['●', '○', '◆', '◇', '★', '☆'][self.rng.gen_range(0..6)],
+            friction: self.rng.gen_range(0.96..0.995),
+            color_ramp,
+        });
+    }
+
+    /// Record the wall-clock time spent in the last `update()`/`render()`
+    /// call, in milliseconds, so the profiler overlay can graph them.
+    fn record_frame_times(&mut self, update_ms: f32, render_ms: f32) {
+        self.counters[self.idx_update_ms].push(update_ms);
+        self.counters[self.idx_render_ms].push(render_ms);
+    }
+
+    /// Compose the whole frame into `back_buffer`, diff it against
+    /// `front_buffer`, and write only the cells that actually changed.
+    /// `MoveTo`/`SetForegroundColor` are only emitted when the cursor
+    /// position or color differs from the last cell written this frame.
     fn render(&mut self) -> Result<()> {
-        let mut stdout = stdout();
-        execute!(stdout, Clear(ClearType::All), MoveTo(0, 0))?;
-        
-        // Render CPU patterns
-        let cpu_section_height = self.height / 3;
-        for row in 0..cpu_section_height {
-            for col in 0..self.width {
-                let intensity = self.calculate_cpu_intensity(col, row);
+        self.compose_frame();
+
+        let mut out = BufWriter::new(stdout());
+        let mut cursor: Option<(u16, u16)> = None;
+        let mut fg: Option<(u8, u8, u8)> = None;
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let idx = y as usize * self.width as usize + x as usize;
+                if self.back_buffer[idx] == self.front_buffer[idx] {
+                    continue;
+                }
+                let cell = self.back_buffer[idx];
+
+                let cursor_in_place = cursor == Some((x, y));
+                if !cursor_in_place {
+                    queue!(out, MoveTo(x, y))?;
+                }
+                if fg != Some(cell.fg) {
+                    queue!(
+                        out,
+                        SetForegroundColor(Color::Rgb { r: cell.fg.0, g: cell.fg.1, b: cell.fg.2 })
+                    )?;
+                    fg = Some(cell.fg);
+                }
+                queue!(out, Print(cell.ch))?;
+
+                cursor = Some((x + 1, y));
+                self.front_buffer[idx] = cell;
+            }
+        }
+
+        queue!(out, ResetColor)?;
+        out.flush()?;
+        Ok(())
+    }
+
+    /// Compose this frame into `back_buffer` by resolving `self.layout` against the
+    /// current terminal size and dispatching each resolved region to its
+    /// bound renderer. A `Processes` view-mode toggle still takes over the
+    /// whole screen, same as before the layout engine existed.
+    fn compose_frame(&mut self) {
+        self.back_buffer.fill(Cell::default());
+
+        if self.view_mode == ViewMode::Processes {
+            self.render_process_table(Rect::full(self.width, self.height));
+            return;
+        }
+
+        let mut regions = Vec::new();
+        self.layout.clone().resolve(Rect::full(self.width, self.height), &mut regions);
+
+        // Particles are decorative and network-tied: if the layout doesn't
+        // place them explicitly, they still roam the whole screen like
+        // before the layout engine existed.
+        let mut drew_particles = false;
+
+        for (kind, rect) in regions {
+            match kind {
+                RendererKind::CpuHeatmap => self.render_cpu_heatmap(rect),
+                RendererKind::MemoryWave => self.render_memory_wave(rect),
+                RendererKind::ProcessTable => self.render_process_table(rect),
+                RendererKind::Profiler => self.render_profiler_panel(rect),
+                RendererKind::Particles => {
+                    self.render_particles(rect);
+                    drew_particles = true;
+                }
+            }
+        }
+
+        if !drew_particles {
+            self.render_particles(Rect::full(self.width, self.height));
+        }
+    }
+
+    fn render_cpu_heatmap(&mut self, rect: Rect) {
+        for row in 0..rect.h {
+            for col in 0..rect.w {
+                let intensity = self.calculate_cpu_intensity(col, row, rect.h);
                 let color = self.gradient.at(intensity as f64).to_rgba8();
                 let char = self.get_pattern_char(intensity);
-                
-                execute!(
-                    stdout,
-                    MoveTo(col, row),
-                    SetForegroundColor(Color::Rgb { r: color[0], g: color[1], b: color[2] }),
-                    Print(char)
-                )?;
+                self.set_cell(rect.x + col, rect.y + row, char, (color[0], color[1], color[2]));
             }
         }
-        
-        // Render memory waves
-        let wave_start = cpu_section_height;
-        let wave_height = self.height / 3;
-        for row in 0..wave_height {
-            for col in 0..self.width {
-                let wave_y = self.calculate_memory_wave(col as usize, row, wave_height);
-                let intensity = if row == wave_y { 1.0 } else { 0.0 };
-                
-                if intensity > 0.0 {
+    }
+
+    fn render_memory_wave(&mut self, rect: Rect) {
+        for row in 0..rect.h {
+            for col in 0..rect.w {
+                let wave_y = self.calculate_memory_wave(col as usize, rect.h);
+                if row == wave_y {
                     let color = self.gradient.at(0.7).to_rgba8();
-                    execute!(
-                        stdout,
-                        MoveTo(col, wave_start + row),
-                        SetForegroundColor(Color::Rgb { r: color[0], g: color[1], b: color[2] }),
-                        Print('▓')
-                    )?;
+                    self.set_cell(rect.x + col, rect.y + row, '▓', (color[0], color[1], color[2]));
                 }
             }
         }
-        
-        // Render particles (only if enabled)
-        if self.particles_enabled {
-            for particle in &self.particles {
-                if particle.life > 0.0 {
-                    let color = self.gradient.at(particle.life as f64).to_rgba8();
-                    execute!(
-                        stdout,
-                        MoveTo(particle.x as u16, particle.y as u16),
-                        SetForegroundColor(Color::Rgb { r: color[0], g: color[1], b: color[2] }),
-                        Print(particle.char)
-                    )?;
-                }
+    }
+
+    fn render_particles(&mut self, rect: Rect) {
+        if !self.particles_enabled {
+            return;
+        }
+        for i in 0..self.particles.len() {
+            let particle = &self.particles[i];
+            if particle.life <= 0.0 {
+                continue;
             }
+            let (px, py) = (particle.x as u16, particle.y as u16);
+            if px < rect.x || px >= rect.x + rect.w || py < rect.y || py >= rect.y + rect.h {
+                continue;
+            }
+            let ramp_len = particle.color_ramp.len();
+            let step = ((1.0 - particle.life).clamp(0.0, 1.0) * (ramp_len - 1) as f32).round() as usize;
+            let color = particle.color_ramp[step.min(ramp_len - 1)];
+            let ch = particle.char;
+            self.set_cell(px, py, ch, color);
         }
-        
-        // Render info panel
-        self.render_info_panel(&mut stdout)?;
-        
-        execute!(stdout, ResetColor)?;
-        stdout.flush()?;
-        Ok(())
     }
-    
-    fn calculate_cpu_intensity(&self, col: u16, row: u16) -> f32 {
+
+    /// CPU intensity at `(col, row)` within a region `section_height`
+    /// cells tall, scaled so every core gets an even vertical band.
+    fn calculate_cpu_intensity(&self, col: u16, row: u16, section_height: u16) -> f32 {
         if self.cpu_history.is_empty() || col as usize >= self.cpu_history[0].len() {
             return 0.0;
         }
-        
-        let cpu_index = (row as usize * self.cpu_history.len()) / (self.height as usize / 3);
+
+        let cpu_index = (row as usize * self.cpu_history.len()) / (section_height.max(1) as usize);
         let cpu_index = cpu_index.min(self.cpu_history.len() - 1);
-        
+
         self.cpu_history[cpu_index][col as usize] / 100.0
     }
-    
-    fn calculate_memory_wave(&self, col: usize, _row: u16, wave_height: u16) -> u16 {
+
+    fn calculate_memory_wave(&self, col: usize, wave_height: u16) -> u16 {
         if col >= self.memory_wave.len() {
             return wave_height / 2;
         }
-        
+
         let base_wave = (self.memory_wave[col] * wave_height as f32) as u16;
         let time_offset = col as f32 * 0.1;
         let wave_offset = (time_offset.sin() * 3.0) as i16;
-        
+
         ((base_wave as i16 + wave_offset).max(0).min(wave_height as i16 - 1)) as u16
     }
     
@@ -241,62 +1035,312 @@ impl LiveScope {
             self.particles.clear(); // Clear existing particles when disabled
         }
     }
-    
-    fn render_info_panel(&mut self, stdout: &mut std::io::Stdout) -> Result<()> {
-        let info_y = self.height - 5;
-        let cpu_usage: f32 = self.system.cpus().iter().map(|cpu| cpu.cpu_usage()).sum::<f32>() / self.system.cpus().len() as f32;
-        let memory_percent = (self.system.used_memory() as f64 / self.system.total_memory() as f64 * 100.0) as u8;
-        
+
+    fn toggle_view_mode(&mut self) {
+        self.view_mode = self.view_mode.toggle();
+        self.process_scroll = 0;
+    }
+
+    fn cycle_process_sort(&mut self) {
+        self.process_sort = self.process_sort.cycle();
+    }
+
+    /// Drain any pending filesystem-change events for the watched gradient
+    /// file and rebuild `self.gradient` if it was touched. Malformed edits
+    /// are ignored so a half-written file never kills the render loop.
+    fn reload_theme_if_changed(&mut self) {
+        let Some(path) = self.theme_file.clone() else {
+            return;
+        };
+        let mut changed = false;
+        while let Ok(res) = self.theme_rx.try_recv() {
+            if res.is_ok() {
+                changed = true;
+            }
+        }
+        if changed {
+            if let Ok(gradient) = load_gradient_file(&path) {
+                self.gradient = gradient;
+            }
+        }
+    }
+
+    /// Cycle through the built-in presets plus the watched gradient file
+    /// (if one was supplied via `--theme`).
+    fn cycle_theme(&mut self) {
+        let total = THEME_PRESETS.len() + self.theme_file.is_some() as usize;
+        self.theme_cursor = (self.theme_cursor + 1) % total;
+        if self.theme_cursor < THEME_PRESETS.len() {
+            self.gradient = preset_gradient(THEME_PRESETS[self.theme_cursor]);
+        } else if let Some(path) = self.theme_file.clone() {
+            if let Ok(gradient) = load_gradient_file(&path) {
+                self.gradient = gradient;
+            }
+        }
+    }
+
+    fn scroll_processes(&mut self, delta: i32) {
+        let max_scroll = self.system.processes().len().saturating_sub(1);
+        self.process_scroll = (self.process_scroll as i32 + delta)
+            .max(0)
+            .min(max_scroll as i32) as usize;
+    }
+
+    /// Render a scrollable, sortable top-style table of the running
+    /// processes within `rect` (the whole screen in `--mode processes`, or
+    /// whatever region a layout binds to `ProcessTable`).
+    fn render_process_table(&mut self, rect: Rect) {
+        let sort = self.process_sort;
+        let mut processes: Vec<_> = self.system.processes().values().collect();
+        match sort {
+            ProcessSort::Cpu => processes.sort_by(|a, b| b.cpu_usage().total_cmp(&a.cpu_usage())),
+            ProcessSort::Memory => processes.sort_by_key(|p| std::cmp::Reverse(p.memory())),
+            ProcessSort::Name => processes.sort_by_key(|p| p.name().to_lowercase()),
+        }
+
+        let rows_height = rect.h.saturating_sub(1);
+        let max_cpu = processes
+            .iter()
+            .map(|p| p.cpu_usage())
+            .fold(0.0_f32, f32::max)
+            .max(1.0);
+
+        // Pull out the owned fields we need before touching `self` again —
+        // `processes` borrows from `self.system`, so it can't still be
+        // alive once we start calling `self.set_str`.
+        let rows: Vec<(u32, String, f32, f64)> = processes
+            .iter()
+            .skip(self.process_scroll)
+            .take(rows_height as usize)
+            .map(|p| {
+                (
+                    p.pid().as_u32(),
+                    p.name().to_string(),
+                    p.cpu_usage(),
+                    p.memory() as f64 / (1024.0 * 1024.0),
+                )
+            })
+            .collect();
+
+        self.set_str(
+            rect.x,
+            rect.y,
+            &format!(
+                "{:>8} {:<24} {:>7} {:>10}  (sort: {} — 's' to cycle, ↑/↓ to scroll)",
+                "PID", "NAME", "CPU%", "MEM", sort.label()
+            ),
+            (255, 255, 255),
+        );
+
+        for (row, (pid, name, cpu, mem_mb)) in rows.into_iter().enumerate() {
+            let color = self.gradient.at((cpu / max_cpu) as f64).to_rgba8();
+            let line = format!("{:>8} {:<24.24} {:>6.1}% {:>8.1}MB", pid, name, cpu, mem_mb);
+            self.set_str(rect.x, rect.y + 1 + row as u16, &line, (color[0], color[1], color[2]));
+        }
+    }
+
+    /// Draw the profiler overlay within `rect`: one sparkline line per
+    /// tracked counter, plus a dedicated frame-time graph scaled against
+    /// the refresh budget so it reads as headroom when under budget, or
+    /// flags an overrun.
+    fn render_profiler_panel(&mut self, rect: Rect) {
+        let panel_y = rect.y;
         let particle_status = if self.particles_enabled { "ON" } else { "OFF" };
-        execute!(
-            stdout,
-            MoveTo(2, info_y),
-            SetForegroundColor(Color::White),
-            Print(format!("LiveScope v0.1.0 | CPU: {:.1}% | RAM: {}% | Particles: {} [{}]", 
-                         cpu_usage, memory_percent, self.particles.len(), particle_status))
-        )?;
-        
-        execute!(
-            stdout,
-            MoveTo(2, info_y + 1),
-            Print("Press 'q' to quit, 'p' to toggle particles")
-        )?;
-        
-        Ok(())
+        let white = (255, 255, 255);
+
+        self.set_str(
+            rect.x,
+            panel_y,
+            &format!(
+                "LiveScope v0.1.0 | Particles: [{}] | refresh budget: {:.0}ms",
+                particle_status, self.refresh_budget_ms
+            ),
+            white,
+        );
+
+        let visible_counters = self.counters.len().min(rect.h.saturating_sub(2) as usize);
+        for i in 0..visible_counters {
+            let row = panel_y + 1 + i as u16;
+            let counter = &self.counters[i];
+            let line = if i == self.idx_update_ms || i == self.idx_render_ms {
+                // Frame-time graphs read against the refresh budget: the
+                // ceiling stays pinned at the budget line so headroom is
+                // always visible, with overrunning frames marked `!` in
+                // place instead of rescaling the graph out from under it.
+                let max = counter.max();
+                let spark = counter.sparkline_against_budget(self.refresh_budget_ms);
+                format!(
+                    "{:>8} {:>6.2}{} avg {:>6.2}{} max {}",
+                    counter.label, counter.avg(), counter.unit, max, counter.unit, spark
+                )
+            } else {
+                format!(
+                    "{:>8} {:>6.1}{} avg {:>6.1}{} max {}",
+                    counter.label,
+                    counter.avg(),
+                    counter.unit,
+                    counter.max(),
+                    counter.unit,
+                    counter.sparkline()
+                )
+            };
+            self.set_str(rect.x, row, &line, white);
+        }
+
+        if visible_counters as u16 + 1 < rect.h {
+            self.set_str(
+                rect.x,
+                panel_y + 1 + visible_counters as u16,
+                "Press 'q' to quit, 'p' to toggle particles, 'm' to toggle process view, 't' to cycle theme",
+                white,
+            );
+        }
+    }
+}
+
+/// Run the `--serve` HTTP endpoints until the process exits. Runs
+/// alongside the terminal UI on the same tokio runtime; never touches
+/// `LiveScope` directly, only the shared `metrics` snapshot.
+async fn serve_metrics(port: u16, refresh_ms: u64, metrics: Arc<Mutex<MetricsSnapshot>>) -> Result<()> {
+    // Loopback-only by default: this is a personal visualizer, not a
+    // service meant to expose system/process metrics to the network.
+    let listener = TcpListener::bind(("127.0.0.1", port)).await?;
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let metrics = metrics.clone();
+        tokio::spawn(async move {
+            let _ = handle_metrics_connection(stream, refresh_ms, metrics).await;
+        });
+    }
+}
+
+async fn handle_metrics_connection(
+    stream: tokio::net::TcpStream,
+    refresh_ms: u64,
+    metrics: Arc<Mutex<MetricsSnapshot>>,
+) -> Result<()> {
+    let (read_half, mut write_half) = stream.into_split();
+    let mut reader = BufReader::new(read_half);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await?;
+    let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+
+    match path {
+        "/metrics" => {
+            let body = metrics.lock().unwrap().to_prometheus();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            write_half.write_all(response.as_bytes()).await?;
+        }
+        "/stream" => {
+            let header = "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: keep-alive\r\n\r\n";
+            write_half.write_all(header.as_bytes()).await?;
+            loop {
+                let body = metrics.lock().unwrap().to_json();
+                if write_half.write_all(format!("data: {body}\n\n").as_bytes()).await.is_err() {
+                    break;
+                }
+                sleep(Duration::from_millis(refresh_ms)).await;
+            }
+        }
+        _ => {
+            let body = "not found";
+            let response = format!(
+                "HTTP/1.1 404 Not Found\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            write_half.write_all(response.as_bytes()).await?;
+        }
     }
+    Ok(())
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = Args::parse();
-    
+
+    // Build (and validate theme/layout for) LiveScope before touching the
+    // terminal at all, so a bad `--theme` path or malformed `--layout`
+    // spec fails with a plain, visible error instead of leaving the
+    // terminal stuck in raw mode on the alternate screen.
+    let mut livescope = LiveScope::new(
+        &args.theme,
+        args.particles,
+        args.refresh,
+        &args.mode,
+        args.layout.as_deref(),
+    )?;
+    let refresh_duration = Duration::from_millis(args.refresh);
+
     enable_raw_mode()?;
     execute!(stdout(), EnterAlternateScreen, Hide)?;
-    
-    let mut livescope = LiveScope::new(&args.theme, args.particles)?;
-    let refresh_duration = Duration::from_millis(args.refresh);
-    
+
+    let metrics = Arc::new(Mutex::new(MetricsSnapshot::default()));
+    if let Some(port) = args.serve {
+        let metrics = metrics.clone();
+        let refresh_ms = args.refresh;
+        tokio::spawn(async move {
+            if let Err(e) = serve_metrics(port, refresh_ms, metrics).await {
+                eprintln!("metrics server on port {port} stopped: {e}");
+            }
+        });
+    }
+
     loop {
         let start = Instant::now();
         
         // Handle input
         if event::poll(Duration::from_millis(0))? {
-            if let Event::Key(key_event) = event::read()? {
-                if key_event.kind == KeyEventKind::Press {
+            match event::read()? {
+                Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
                     match key_event.code {
                         KeyCode::Char('q') => break,
                         KeyCode::Char('p') => {
                             livescope.toggle_particles();
                         }
+                        KeyCode::Char('m') => {
+                            livescope.toggle_view_mode();
+                        }
+                        KeyCode::Char('s') => {
+                            livescope.cycle_process_sort();
+                        }
+                        KeyCode::Up => {
+                            livescope.scroll_processes(-1);
+                        }
+                        KeyCode::Down => {
+                            livescope.scroll_processes(1);
+                        }
+                        KeyCode::Char('t') => {
+                            livescope.cycle_theme();
+                        }
                         _ => {}
                     }
                 }
+                Event::Resize(width, height) => {
+                    livescope.resize(width, height);
+                    execute!(stdout(), Clear(ClearType::All))?;
+                }
+                _ => {}
             }
         }
-        
+
+        let update_start = Instant::now();
         livescope.update();
+        let update_ms = update_start.elapsed().as_secs_f32() * 1000.0;
+
+        let render_start = Instant::now();
         livescope.render()?;
-        
+        let render_ms = render_start.elapsed().as_secs_f32() * 1000.0;
+
+        livescope.record_frame_times(update_ms, render_ms);
+        if args.serve.is_some() {
+            *metrics.lock().unwrap() = livescope.snapshot();
+        }
+
         let elapsed = start.elapsed();
         if elapsed < refresh_duration {
             sleep(refresh_duration - elapsed).await;